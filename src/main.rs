@@ -0,0 +1,8 @@
+mod plugin;
+
+use nu_plugin::{serve_plugin, MsgPackSerializer};
+use plugin::UlidPlugin;
+
+fn main() {
+    serve_plugin(&UlidPlugin::new(), MsgPackSerializer)
+}
@@ -2,7 +2,7 @@ use std::time::{Duration, SystemTime};
 
 use chrono::{DateTime, Local, Utc};
 use nu_plugin::{EngineInterface, EvaluatedCall, Plugin, SimplePluginCommand};
-use nu_protocol::{Example, LabeledError, Signature, Type, Value};
+use nu_protocol::{Example, LabeledError, Signature, Span, SyntaxShape, Type, Value};
 use ulid::Ulid;
 
 pub struct UlidPlugin;
@@ -15,7 +15,18 @@ impl UlidPlugin {
 
 impl Plugin for UlidPlugin {
     fn commands(&self) -> Vec<Box<dyn nu_plugin::PluginCommand<Plugin = Self>>> {
-        vec![Box::new(RandomUlid), Box::new(ParseUlid)]
+        vec![
+            Box::new(RandomUlid),
+            Box::new(ParseUlid),
+            Box::new(GenerateSeqUlid),
+            Box::new(UlidToUuid),
+            Box::new(UlidFromUuid),
+            Box::new(RandomUuidV7),
+            Box::new(ParseUuidV7),
+            Box::new(SortUlid),
+            Box::new(RandomNrid),
+            Box::new(ParseNrid),
+        ]
     }
 
     fn version(&self) -> String {
@@ -81,16 +92,16 @@ impl SimplePluginCommand for RandomUlid {
             )
     }
 
-    fn examples(&self) -> Vec<Example> {
+    fn examples(&self) -> Vec<Example<'_>> {
         vec![
             Example {
-                description: "Generate a random ulid based on the current time".into(),
-                example: "random ulid".into(),
+                description: "Generate a random ulid based on the current time",
+                example: "random ulid",
                 result: Some(Value::test_string(Ulid::new().to_string())),
             },
             Example {
-                description: "Generate a random ulid based on the given timestamp".into(),
-                example: "2024-03-19T11:46:00 | random ulid".into(),
+                description: "Generate a random ulid based on the given timestamp",
+                example: "2024-03-19T11:46:00 | random ulid",
                 result: Some(Value::test_string(
                     Ulid::from_datetime(
                         SystemTime::UNIX_EPOCH + Duration::from_nanos(1710848760000000000),
@@ -100,9 +111,8 @@ impl SimplePluginCommand for RandomUlid {
             },
             Example {
                 description:
-                    "Generate a ulid based on the current time with the random portion all set to 0"
-                        .into(),
-                example: "random ulid --zeroed".into(),
+                    "Generate a ulid based on the current time with the random portion all set to 0",
+                example: "random ulid --zeroed",
                 result: Some(Value::test_string(
                     Ulid::from_parts(unix_millis(None), 0).to_string(),
                 )),
@@ -117,26 +127,7 @@ impl SimplePluginCommand for RandomUlid {
         call: &EvaluatedCall,
         input: &Value,
     ) -> Result<Value, LabeledError> {
-        let (timestamp, random): (Option<SystemTime>, UlidRandom) = match input {
-            Value::Nothing { .. } => (None, self.selected_randomness(call, None)?),
-            Value::Date { val, .. } => (Some((*val).into()), self.selected_randomness(call, None)?),
-            Value::Record { val, .. } => (
-                val.get(K_TS)
-                    .map(|ts| ts.as_date())
-                    .transpose()?
-                    .map(|ts| ts.into()),
-                self.selected_randomness(call, val.get(K_RND))?,
-            ),
-            _ => {
-                return Err(LabeledError::new("Invalid input").with_label(
-                    format!(
-                        "Input type of {} is not supported",
-                        input.get_type().to_string()
-                    ),
-                    input.span(),
-                ))
-            }
-        };
+        let (timestamp, random) = resolve_timestamp_and_random(call, input)?;
 
         Ok(Value::string(
             self.generate(timestamp, random).to_string(),
@@ -152,41 +143,72 @@ enum UlidRandom {
     Ones,
 }
 
-impl RandomUlid {
-    fn selected_randomness(
-        &self,
-        call: &EvaluatedCall,
-        input: Option<&Value>,
-    ) -> Result<UlidRandom, LabeledError> {
-        match (
-            call.has_flag("zeroed").unwrap(),
-            call.has_flag("oned").unwrap(),
-            input,
-        ) {
-            (true, true, _) => Err(LabeledError::new("Flag error")
-                .with_label("Cannot set --zeroed and --oned at the same time", call.head)),
-            (true, false, _) => Ok(UlidRandom::Zeros),
-            (false, true, _) => Ok(UlidRandom::Ones),
-            (false, false, None) => Ok(UlidRandom::Random),
-            (false, false, Some(input)) => match input {
-                Value::String { val, internal_span } => {
-                    Ok(UlidRandom::Set(val.parse::<u128>().map_err(|e| {
-                        LabeledError::new("Invalid number")
-                            .with_label(e.to_string(), *internal_span)
-                    })?))
-                }
-                Value::Int { val, .. } => Ok(UlidRandom::Set(*val as u128)),
-                _ => Err(LabeledError::new("Invalid number").with_label(
-                    format!(
-                        "{} is not a valid number",
-                        input.to_abbreviated_string(&nu_protocol::Config::default())
-                    ),
-                    input.span(),
-                )),
-            },
-        }
+/// Parse the `--zeroed`/`--oned` switches and an optional `random` input
+/// value into the randomness strategy to use. Shared by every command family
+/// (ulid, uuidv7, ...) that generates a time-ordered identifier with a
+/// random tail.
+#[allow(clippy::result_large_err)]
+fn selected_randomness(
+    call: &EvaluatedCall,
+    input: Option<&Value>,
+) -> Result<UlidRandom, LabeledError> {
+    match (
+        call.has_flag("zeroed").unwrap(),
+        call.has_flag("oned").unwrap(),
+        input,
+    ) {
+        (true, true, _) => Err(LabeledError::new("Flag error")
+            .with_label("Cannot set --zeroed and --oned at the same time", call.head)),
+        (true, false, _) => Ok(UlidRandom::Zeros),
+        (false, true, _) => Ok(UlidRandom::Ones),
+        (false, false, None) => Ok(UlidRandom::Random),
+        (false, false, Some(input)) => match input {
+            Value::String { val, internal_span } => {
+                Ok(UlidRandom::Set(val.parse::<u128>().map_err(|e| {
+                    LabeledError::new("Invalid number").with_label(e.to_string(), *internal_span)
+                })?))
+            }
+            Value::Int { val, .. } => Ok(UlidRandom::Set(*val as u128)),
+            _ => Err(LabeledError::new("Invalid number").with_label(
+                format!(
+                    "{} is not a valid number",
+                    input.to_abbreviated_string(&nu_protocol::Config::default())
+                ),
+                input.span(),
+            )),
+        },
+    }
+}
+
+/// Resolve the common `Nothing`/`Date`/`Record{timestamp, random}` input
+/// shapes shared by every `random <format>` command into a timestamp and a
+/// randomness strategy.
+#[allow(clippy::result_large_err)]
+fn resolve_timestamp_and_random(
+    call: &EvaluatedCall,
+    input: &Value,
+) -> Result<(Option<SystemTime>, UlidRandom), LabeledError> {
+    match input {
+        Value::Nothing { .. } => Ok((None, selected_randomness(call, None)?)),
+        Value::Date { val, .. } => Ok((Some((*val).into()), selected_randomness(call, None)?)),
+        Value::Record { val, .. } => Ok((
+            val.get(K_TS)
+                .map(|ts| ts.as_date())
+                .transpose()?
+                .map(|ts| ts.into()),
+            selected_randomness(call, val.get(K_RND))?,
+        )),
+        _ => Err(LabeledError::new("Invalid input").with_label(
+            format!(
+                "Input type of {} is not supported",
+                input.get_type()
+            ),
+            input.span(),
+        )),
     }
+}
 
+impl RandomUlid {
     fn generate(&self, timestamp: Option<SystemTime>, random: UlidRandom) -> Ulid {
         match (timestamp, random) {
             (None, UlidRandom::Random) => Ulid::new(),
@@ -234,10 +256,10 @@ impl SimplePluginCommand for ParseUlid {
             )])
     }
 
-    fn examples(&self) -> Vec<Example> {
+    fn examples(&self) -> Vec<Example<'_>> {
         vec![Example {
-            description: "Generate a ulid and parse out the date portion".into(),
-            example: "random ulid | parse ulid | get timestamp".into(),
+            description: "Generate a ulid and parse out the date portion",
+            example: "random ulid | parse ulid | get timestamp",
             result: Some(Value::test_date(Local::now().fixed_offset())),
         }]
     }
@@ -269,3 +291,871 @@ impl SimplePluginCommand for ParseUlid {
         ))
     }
 }
+
+/// The random component of a ulid is 80 bits wide.
+const MAX_RANDOM: u128 = (1u128 << 80) - 1;
+
+/// Compute the next ulid in a strictly increasing sequence, following the
+/// monotonic technique from rusty_ulid's `next_monotonic`/`next_strictly_monotonic`:
+/// if the new timestamp has not advanced past the previous ulid's (it's
+/// either equal, or the clock moved backward), the random component is
+/// incremented by one instead of redrawn, and the previous timestamp is
+/// reused. If that increment would overflow the 80-bit random field, the
+/// timestamp is rolled forward by one millisecond and the random component
+/// is redrawn, unless `strict` is set, in which case an error is returned
+/// instead.
+#[allow(clippy::result_large_err)]
+fn next_monotonic(
+    previous: Option<Ulid>,
+    timestamp_ms: u64,
+    strict: bool,
+    span: Span,
+) -> Result<Ulid, LabeledError> {
+    match previous {
+        Some(prev) if prev.timestamp_ms() >= timestamp_ms => {
+            let timestamp_ms = prev.timestamp_ms();
+            if prev.random() >= MAX_RANDOM {
+                if strict {
+                    Err(LabeledError::new("Monotonic overflow").with_label(
+                        "random component exhausted for this millisecond",
+                        span,
+                    ))
+                } else {
+                    Ok(Ulid::from_parts(timestamp_ms + 1, Ulid::new().random()))
+                }
+            } else {
+                Ok(Ulid::from_parts(timestamp_ms, prev.random() + 1))
+            }
+        }
+        _ => Ok(Ulid::from_parts(timestamp_ms, Ulid::new().random())),
+    }
+}
+
+pub struct GenerateSeqUlid;
+
+impl SimplePluginCommand for GenerateSeqUlid {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid generate-seq"
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a strictly increasing sequence of ulids"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec![
+                "generate".into(),
+                "ulid".into(),
+                "monotonic".into(),
+                "sequence".into(),
+            ])
+            .input_output_types(vec![
+                (Type::Nothing, Type::List(Box::new(Type::String))),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "Number of ulids to generate",
+                Some('c'),
+            )
+            .switch(
+                "strict",
+                "Error instead of rolling the timestamp forward when the random component of a millisecond is exhausted",
+                None,
+            )
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Generate a sequence of 5 strictly increasing ulids",
+                example: "ulid generate-seq --count 5",
+                result: None,
+            },
+            Example {
+                description: "Generate one strictly increasing ulid per item in a list",
+                example: "[a b c] | ulid generate-seq",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let count = match call.get_flag::<i64>("count")? {
+            Some(count) => count.max(0) as usize,
+            None => match input {
+                Value::List { vals, .. } => vals.len(),
+                _ => 1,
+            },
+        };
+        let strict = call.has_flag("strict").unwrap();
+
+        let mut previous: Option<Ulid> = None;
+        let mut ulids = Vec::with_capacity(count);
+        for _ in 0..count {
+            let next = next_monotonic(previous, unix_millis(None), strict, call.head)?;
+            previous = Some(next);
+            ulids.push(Value::string(next.to_string(), call.head));
+        }
+
+        Ok(Value::list(ulids, call.head))
+    }
+}
+
+/// Format a 128-bit value as a canonical hyphenated UUID string.
+fn format_uuid(value: u128) -> String {
+    let bytes = value.to_be_bytes();
+    format!(
+        "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        bytes[0],
+        bytes[1],
+        bytes[2],
+        bytes[3],
+        bytes[4],
+        bytes[5],
+        bytes[6],
+        bytes[7],
+        bytes[8],
+        bytes[9],
+        bytes[10],
+        bytes[11],
+        bytes[12],
+        bytes[13],
+        bytes[14],
+        bytes[15],
+    )
+}
+
+/// Parse a canonical hyphenated UUID string into its underlying 128-bit value.
+fn parse_uuid(input: &str) -> Result<u128, String> {
+    let hex: String = input.chars().filter(|c| *c != '-').collect();
+    if hex.len() != 32 {
+        return Err(format!("{input} is not a valid uuid"));
+    }
+    u128::from_str_radix(&hex, 16).map_err(|e| e.to_string())
+}
+
+pub struct UlidToUuid;
+
+impl SimplePluginCommand for UlidToUuid {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid to-uuid"
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a ulid into the uuid encoding of the same underlying value"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["ulid".into(), "uuid".into(), "convert".into()])
+            .input_output_types(vec![(Type::String, Type::String)])
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Convert a ulid to its uuid representation",
+            example: "random ulid | ulid to-uuid",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let ulid: Ulid = input.coerce_str()?.parse::<Ulid>().map_err(|e| {
+            LabeledError::new("Failed to parse ulid").with_label(e.to_string(), input.span())
+        })?;
+
+        Ok(Value::string(
+            format_uuid(u128::from(ulid)),
+            call.head,
+        ))
+    }
+}
+
+pub struct UlidFromUuid;
+
+impl SimplePluginCommand for UlidFromUuid {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid from-uuid"
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a uuid into the ulid encoding of the same underlying value"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["ulid".into(), "uuid".into(), "convert".into()])
+            .input_output_types(vec![(Type::String, Type::String)])
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Convert a uuid to its ulid representation",
+            example: "\"01890a5d-ac96-774b-bcce-b302099a8057\" | ulid from-uuid",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let value = parse_uuid(&input.coerce_str()?).map_err(|e| {
+            LabeledError::new("Failed to parse uuid").with_label(e, input.span())
+        })?;
+
+        Ok(Value::string(Ulid::from(value).to_string(), call.head))
+    }
+}
+
+/// UUIDv7 packs the same unix-millisecond timestamp as a ulid into the top
+/// 48 bits, followed by a 4-bit version and 2-bit variant, leaving 74 bits
+/// (12 + 62, split either side of the variant) for the random tail.
+const UUIDV7_RAND_B_BITS: u32 = 62;
+const UUIDV7_RAND_A_MASK: u128 = 0xFFF;
+const UUIDV7_RAND_B_MASK: u128 = (1u128 << UUIDV7_RAND_B_BITS) - 1;
+
+fn build_uuidv7(timestamp_ms: u64, random: u128) -> u128 {
+    let rand_a = (random >> UUIDV7_RAND_B_BITS) & UUIDV7_RAND_A_MASK;
+    let rand_b = random & UUIDV7_RAND_B_MASK;
+    ((timestamp_ms as u128 & 0xFFFF_FFFF_FFFF) << 80)
+        | (0x7 << 76)
+        | (rand_a << 64)
+        | (0b10 << 62)
+        | rand_b
+}
+
+fn split_uuidv7(value: u128) -> Result<(u64, u128), String> {
+    let version = (value >> 76) & 0xF;
+    if version != 0x7 {
+        return Err(format!("expected a UUIDv7, found version {version} instead"));
+    }
+    let variant = (value >> 62) & 0b11;
+    if variant != 0b10 {
+        return Err(format!(
+            "expected a UUIDv7, found variant {variant:02b} instead of 10"
+        ));
+    }
+
+    let timestamp_ms = ((value >> 80) & 0xFFFF_FFFF_FFFF) as u64;
+    let rand_a = (value >> 64) & UUIDV7_RAND_A_MASK;
+    let rand_b = value & UUIDV7_RAND_B_MASK;
+    Ok((timestamp_ms, (rand_a << UUIDV7_RAND_B_BITS) | rand_b))
+}
+
+pub struct RandomUuidV7;
+
+impl SimplePluginCommand for RandomUuidV7 {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "random uuidv7"
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random uuidv7"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["generate".into(), "uuid".into(), "uuidv7".into()])
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::Date, Type::String),
+                (
+                    Type::Record(Box::new([
+                        (K_TS.into(), Type::Date),
+                        (K_RND.into(), Type::String),
+                    ])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([
+                        (K_TS.into(), Type::Date),
+                        (K_RND.into(), Type::Int),
+                    ])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([(K_TS.into(), Type::Date)])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([(K_RND.into(), Type::String)])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([(K_RND.into(), Type::Int)])),
+                    Type::String,
+                ),
+            ])
+            .switch(
+                "zeroed",
+                "Fill the random portion of the uuidv7 with zeros",
+                Some('0'),
+            )
+            .switch(
+                "oned",
+                "Fill the random portion of the uuidv7 with ones",
+                Some('1'),
+            )
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Generate a random uuidv7 based on the current time",
+                example: "random uuidv7",
+                result: None,
+            },
+            Example {
+                description:
+                    "Generate a uuidv7 based on the current time with the random portion all set to 0",
+                example: "random uuidv7 --zeroed",
+                result: Some(Value::test_string(format_uuid(build_uuidv7(
+                    unix_millis(None),
+                    0,
+                )))),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let (timestamp, random) = resolve_timestamp_and_random(call, input)?;
+        let random = match random {
+            UlidRandom::Random => Ulid::new().random(),
+            UlidRandom::Set(r) => r,
+            UlidRandom::Zeros => 0,
+            UlidRandom::Ones => u128::MAX,
+        };
+
+        Ok(Value::string(
+            format_uuid(build_uuidv7(unix_millis(timestamp), random)),
+            call.head,
+        ))
+    }
+}
+
+pub struct ParseUuidV7;
+
+impl SimplePluginCommand for ParseUuidV7 {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "parse uuidv7"
+    }
+
+    fn usage(&self) -> &str {
+        "Parse a uuidv7 into a date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["parse".into(), "uuid".into(), "uuidv7".into(), "date".into()])
+            .input_output_types(vec![(
+                Type::String,
+                Type::Record(Box::new([
+                    (K_TS.into(), Type::Date),
+                    (K_RND.into(), Type::String),
+                ])),
+            )])
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Generate a uuidv7 and parse out the date portion",
+            example: "random uuidv7 | parse uuidv7 | get timestamp",
+            result: Some(Value::test_date(Local::now().fixed_offset())),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let value = parse_uuid(&input.coerce_str()?).map_err(|e| {
+            LabeledError::new("Failed to parse uuidv7").with_label(e, input.span())
+        })?;
+        let (timestamp_ms, random) = split_uuidv7(value).map_err(|e| {
+            LabeledError::new("Failed to parse uuidv7").with_label(e, input.span())
+        })?;
+
+        let date: DateTime<Utc> =
+            (SystemTime::UNIX_EPOCH + Duration::from_millis(timestamp_ms)).into();
+        let date = Value::date(date.fixed_offset(), call.head);
+        Ok(Value::record(
+            [
+                (K_TS.into(), date),
+                (K_RND.into(), Value::string(random.to_string(), call.head)),
+            ]
+            .into_iter()
+            .collect(),
+            call.head,
+        ))
+    }
+}
+
+pub struct SortUlid;
+
+impl SimplePluginCommand for SortUlid {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid sort"
+    }
+
+    fn usage(&self) -> &str {
+        "Sort a list of ulids by their underlying 128-bit value"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["sort".into(), "ulid".into(), "order".into()])
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::String)),
+                Type::List(Box::new(Type::String)),
+            )])
+            .switch("reverse", "Sort in descending order", Some('r'))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Sort a list of ulids into time order",
+            example: "[01ARZ3NDEKTSV4RRFFQ69G5FAV 01ARZ3NDEKTSV4RRFFQ69G5FAU] | ulid sort",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let reverse = call.has_flag("reverse").unwrap();
+
+        let Value::List { vals, .. } = input else {
+            return Err(LabeledError::new("Invalid input").with_label(
+                format!(
+                    "Input type of {} is not supported",
+                    input.get_type()
+                ),
+                input.span(),
+            ));
+        };
+
+        #[allow(clippy::result_large_err)]
+        let mut ulids = vals
+            .iter()
+            .map(|val| {
+                val.coerce_str()?.parse::<Ulid>().map_err(|e| {
+                    LabeledError::new("Failed to parse ulid").with_label(e.to_string(), val.span())
+                })
+            })
+            .collect::<Result<Vec<Ulid>, LabeledError>>()?;
+
+        ulids.sort();
+        if reverse {
+            ulids.reverse();
+        }
+
+        Ok(Value::list(
+            ulids
+                .into_iter()
+                .map(|ulid| Value::string(ulid.to_string(), call.head))
+                .collect(),
+            call.head,
+        ))
+    }
+}
+
+/// NRID layout: 64-bit unix seconds + 32-bit sub-second nanoseconds + 64-bit
+/// random tail, 160 bits total, which divides evenly into 32 Crockford
+/// base32 characters (5 bits each) with no partial trailing group.
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const NRID_BYTES: usize = 20;
+
+fn encode_crockford(bytes: &[u8; NRID_BYTES]) -> String {
+    let mut out = String::with_capacity(32);
+    let mut buffer: u32 = 0;
+    let mut bits = 0u32;
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            let idx = ((buffer >> bits) & 0x1F) as usize;
+            out.push(CROCKFORD_ALPHABET[idx] as char);
+        }
+    }
+    out
+}
+
+fn decode_crockford(input: &str) -> Result<[u8; NRID_BYTES], String> {
+    if input.len() != 32 {
+        return Err(format!("{input} is not a valid nrid"));
+    }
+
+    let mut buffer: u32 = 0;
+    let mut bits = 0u32;
+    let mut bytes = [0u8; NRID_BYTES];
+    let mut byte_idx = 0;
+    for c in input.chars() {
+        let digit = c.to_ascii_uppercase();
+        let value = CROCKFORD_ALPHABET
+            .iter()
+            .position(|&a| a as char == digit)
+            .ok_or_else(|| format!("'{c}' is not a valid crockford base32 character"))?
+            as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes[byte_idx] = ((buffer >> bits) & 0xFF) as u8;
+            byte_idx += 1;
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn build_nrid(seconds: u64, nanos: u32, random: u64) -> [u8; NRID_BYTES] {
+    let mut bytes = [0u8; NRID_BYTES];
+    bytes[0..8].copy_from_slice(&seconds.to_be_bytes());
+    bytes[8..12].copy_from_slice(&nanos.to_be_bytes());
+    bytes[12..20].copy_from_slice(&random.to_be_bytes());
+    bytes
+}
+
+fn split_nrid(bytes: &[u8; NRID_BYTES]) -> (u64, u32, u64) {
+    let seconds = u64::from_be_bytes(bytes[0..8].try_into().unwrap());
+    let nanos = u32::from_be_bytes(bytes[8..12].try_into().unwrap());
+    let random = u64::from_be_bytes(bytes[12..20].try_into().unwrap());
+    (seconds, nanos, random)
+}
+
+/// Turn a nrid's raw seconds/nanos fields into a `SystemTime`. `nanos` comes
+/// straight off the wire, so it isn't guaranteed to be a sub-second
+/// remainder and may carry into `seconds`; both the carry and the
+/// resulting point in time can overflow for a crafted/corrupt nrid, so this
+/// returns an error instead of letting `Duration::new` or `SystemTime`'s
+/// arithmetic panic.
+fn nrid_timestamp(seconds: u64, nanos: u32) -> Result<SystemTime, String> {
+    let extra_secs = (nanos / 1_000_000_000) as u64;
+    let nanos = nanos % 1_000_000_000;
+    let seconds = seconds
+        .checked_add(extra_secs)
+        .ok_or("timestamp seconds overflowed")?;
+    SystemTime::UNIX_EPOCH
+        .checked_add(Duration::new(seconds, nanos))
+        .ok_or_else(|| "timestamp is out of range".to_string())
+}
+
+pub struct RandomNrid;
+
+impl SimplePluginCommand for RandomNrid {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "random nrid"
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random, nanosecond-precision time-correlated id"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["generate".into(), "nrid".into(), "ulid".into()])
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::Date, Type::String),
+                (
+                    Type::Record(Box::new([
+                        (K_TS.into(), Type::Date),
+                        (K_RND.into(), Type::String),
+                    ])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([
+                        (K_TS.into(), Type::Date),
+                        (K_RND.into(), Type::Int),
+                    ])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([(K_TS.into(), Type::Date)])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([(K_RND.into(), Type::String)])),
+                    Type::String,
+                ),
+                (
+                    Type::Record(Box::new([(K_RND.into(), Type::Int)])),
+                    Type::String,
+                ),
+            ])
+            .switch(
+                "zeroed",
+                "Fill the random portion of the nrid with zeros",
+                Some('0'),
+            )
+            .switch(
+                "oned",
+                "Fill the random portion of the nrid with ones",
+                Some('1'),
+            )
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Generate a random nrid based on the current time",
+            example: "random nrid",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let (timestamp, random) = resolve_timestamp_and_random(call, input)?;
+        let since_epoch = timestamp
+            .unwrap_or_else(SystemTime::now)
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        let random = match random {
+            UlidRandom::Random => Ulid::new().random() as u64,
+            UlidRandom::Set(r) => r as u64,
+            UlidRandom::Zeros => 0,
+            UlidRandom::Ones => u64::MAX,
+        };
+
+        Ok(Value::string(
+            encode_crockford(&build_nrid(
+                since_epoch.as_secs(),
+                since_epoch.subsec_nanos(),
+                random,
+            )),
+            call.head,
+        ))
+    }
+}
+
+pub struct ParseNrid;
+
+impl SimplePluginCommand for ParseNrid {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "parse nrid"
+    }
+
+    fn usage(&self) -> &str {
+        "Parse a nrid into a date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .search_terms(vec!["parse".into(), "nrid".into(), "date".into()])
+            .input_output_types(vec![(
+                Type::String,
+                Type::Record(Box::new([
+                    (K_TS.into(), Type::Date),
+                    (K_RND.into(), Type::String),
+                ])),
+            )])
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Generate a nrid and parse out the date portion",
+            example: "random nrid | parse nrid | get timestamp",
+            result: Some(Value::test_date(Local::now().fixed_offset())),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &UlidPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let bytes = decode_crockford(&input.coerce_str()?).map_err(|e| {
+            LabeledError::new("Failed to parse nrid").with_label(e, input.span())
+        })?;
+        let (seconds, nanos, random) = split_nrid(&bytes);
+        let timestamp = nrid_timestamp(seconds, nanos).map_err(|e| {
+            LabeledError::new("Failed to parse nrid").with_label(e, input.span())
+        })?;
+        let date: DateTime<Utc> = timestamp.into();
+        let date = Value::date(date.fixed_offset(), call.head);
+        Ok(Value::record(
+            [
+                (K_TS.into(), date),
+                (K_RND.into(), Value::string(random.to_string(), call.head)),
+            ]
+            .into_iter()
+            .collect(),
+            call.head,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuid_round_trips_through_its_canonical_string_form() {
+        let value = 0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128;
+        let formatted = format_uuid(value);
+        assert_eq!(formatted, "01234567-89ab-cdef-0123-456789abcdef");
+        assert_eq!(parse_uuid(&formatted).unwrap(), value);
+    }
+
+    #[test]
+    fn parse_uuid_rejects_malformed_input() {
+        assert!(parse_uuid("not-a-uuid").is_err());
+        assert!(parse_uuid("0123456789abcdef0123456789abcde").is_err());
+    }
+
+    #[test]
+    fn uuidv7_round_trips_its_timestamp_and_random_bits() {
+        let timestamp_ms = 0x0001_8e3f_2a1b_u64;
+        let random = 0x3_ffff_ffff_ffff_ffffu128;
+        let value = build_uuidv7(timestamp_ms, random);
+        let (ts, rand) = split_uuidv7(value).unwrap();
+        assert_eq!(ts, timestamp_ms);
+        assert_eq!(rand, random);
+    }
+
+    #[test]
+    fn split_uuidv7_rejects_non_v7_version_and_variant() {
+        // A uuidv7 value with the version nibble forced to 0x4 (uuidv4).
+        let wrong_version = build_uuidv7(0x0001_8e3f_2a1b, 0) & !(0xFu128 << 76) | (0x4u128 << 76);
+        assert!(split_uuidv7(wrong_version).is_err());
+
+        // A uuidv7 value with the variant bits forced to 0b00 instead of 0b10.
+        let wrong_variant = build_uuidv7(0x0001_8e3f_2a1b, 0) & !(0b11u128 << 62);
+        assert!(split_uuidv7(wrong_variant).is_err());
+    }
+
+    #[test]
+    fn crockford_round_trips_arbitrary_bytes() {
+        let mut bytes = [0u8; NRID_BYTES];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = (i * 7) as u8;
+        }
+        let encoded = encode_crockford(&bytes);
+        let decoded = decode_crockford(&encoded).unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn decode_crockford_rejects_wrong_length_and_bad_characters() {
+        assert!(decode_crockford("too-short").is_err());
+        assert!(decode_crockford(&"!".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn nrid_round_trips_seconds_nanos_and_random() {
+        let bytes = build_nrid(1_700_000_000, 123_456_789, 0xdead_beef_cafe_babe);
+        let (seconds, nanos, random) = split_nrid(&bytes);
+        assert_eq!(seconds, 1_700_000_000);
+        assert_eq!(nanos, 123_456_789);
+        assert_eq!(random, 0xdead_beef_cafe_babe);
+    }
+
+    #[test]
+    fn nrid_timestamp_accepts_ordinary_values() {
+        let timestamp = nrid_timestamp(1_700_000_000, 500_000_000).unwrap();
+        assert_eq!(
+            timestamp,
+            SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 500_000_000)
+        );
+    }
+
+    #[test]
+    fn nrid_timestamp_errors_instead_of_panicking_on_a_crafted_overflow() {
+        // A decoded nanos field is just 32 arbitrary bits, so it isn't bound
+        // to a sub-second remainder; paired with a seconds value near
+        // u64::MAX the carry must not panic inside Duration::new.
+        assert!(nrid_timestamp(u64::MAX, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn next_monotonic_increments_random_within_the_same_millisecond() {
+        let span = Span::test_data();
+        let first = next_monotonic(None, 1_000, false, span).unwrap();
+        let second = next_monotonic(Some(first), 1_000, false, span).unwrap();
+        assert_eq!(second.timestamp_ms(), first.timestamp_ms());
+        assert_eq!(second.random(), first.random() + 1);
+        assert!(second > first);
+    }
+
+    #[test]
+    fn next_monotonic_draws_fresh_randomness_for_a_later_millisecond() {
+        let span = Span::test_data();
+        let first = next_monotonic(None, 1_000, false, span).unwrap();
+        let second = next_monotonic(Some(first), 1_001, false, span).unwrap();
+        assert_eq!(second.timestamp_ms(), 1_001);
+        assert!(second > first);
+    }
+
+    #[test]
+    fn next_monotonic_stays_increasing_when_the_clock_moves_backward() {
+        let span = Span::test_data();
+        let first = next_monotonic(None, 1_000, false, span).unwrap();
+        // The system clock slewed backward (e.g. NTP adjustment); the
+        // sequence must still advance rather than minting a ulid at the
+        // now-smaller timestamp.
+        let second = next_monotonic(Some(first), 500, false, span).unwrap();
+        assert_eq!(second.timestamp_ms(), first.timestamp_ms());
+        assert_eq!(second.random(), first.random() + 1);
+        assert!(second > first);
+    }
+}